@@ -1,6 +1,9 @@
 use super::*;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
 use crate::sys;
+use futures_channel::oneshot;
 
 /// Represents the result of an loaditem operation, ready to be processed.
 #[derive(Clone, Debug)]
@@ -59,10 +62,25 @@ unsafe impl Callback for SteamInventoryFullUpdate {
     }
 }
 
+type PendingResults = Mutex<HashMap<sys::SteamInventoryResult_t, oneshot::Sender<Result<Vec<SteamItemDetails>, InventoryError>>>>;
+
+/// Wraps a raw `ISteamInventory` pointer so it can be captured by the `'static` callback
+/// closure driving the async layer. Safe because Steam callbacks are only ever dispatched
+/// on the thread that calls `run_callbacks`.
+struct InventoryPtr(*mut sys::ISteamInventory);
+unsafe impl Send for InventoryPtr {}
+
+type CatalogCache = Mutex<HashMap<SteamItemDef, HashMap<String, String>>>;
+
 /// Provides access to the Steam inventory interface.
 pub struct Inventory<Manager> {
     pub(crate) inventory: *mut sys::ISteamInventory,
     pub(crate) _inner: Arc<Inner<Manager>>,
+    pub(crate) async_pending: Arc<PendingResults>,
+    pub(crate) async_registered: Arc<AtomicBool>,
+    pub(crate) catalog_cache: Arc<CatalogCache>,
+    pub(crate) catalog_need_reload: Arc<AtomicBool>,
+    pub(crate) catalog_registered: Arc<AtomicBool>,
 }
 
 impl<Manager> Inventory<Manager> {
@@ -78,48 +96,46 @@ impl<Manager> Inventory<Manager> {
     }
 
     pub fn get_item_definitions_ids(&self) -> Result<Vec<sys::SteamItemDef_t>, InventoryError> {
-        let mut item_defs_count = 0;
-        unsafe {
-            if !sys::SteamAPI_ISteamInventory_GetItemDefinitionIDs(
-                self.inventory,
-                std::ptr::null_mut(),
-                &mut item_defs_count,
-            ) {
-                return Err(InventoryError::GetItemDefinitionIDsFailed);
-            }
-
-            let mut item_defs_array: Vec<sys::SteamItemDef_t> = Vec::with_capacity(item_defs_count as usize);
-            if sys::SteamAPI_ISteamInventory_GetItemDefinitionIDs(
-                self.inventory,
-                item_defs_array.as_mut_ptr(),
-                &mut item_defs_count,
-            ) {
-                item_defs_array.set_len(item_defs_count as usize);
-                Ok(item_defs_array)
-            } else {
-                Err(InventoryError::GetItemDefinitionIDsFailed)
-            }
-        }
+        get_item_definitions_ids_raw(self.inventory)
     }
 
     pub fn get_item_definition_property(&self, item_def: sys::SteamItemDef_t, property_name: &str) -> Result<String, InventoryError> {
+        get_item_definition_property_raw(self.inventory, item_def, property_name)
+    }
+
+    /// Reads a dynamic, per-instance property (e.g. a kill counter or trade-up timestamp)
+    /// off a single item within a result set. `item_index` is the zero-based index into
+    /// the array returned by [`Inventory::get_result_items`]. Follows the same two-pass
+    /// convention as [`Inventory::get_item_definition_property`].
+    pub fn get_result_item_property(
+        &self,
+        result_handle: sys::SteamInventoryResult_t,
+        item_index: u32,
+        property_name: &str,
+    ) -> Result<String, InventoryError> {
         let property_name = CString::new(property_name).expect("CString::new failed");
         let mut value_len = 0;
         unsafe {
-            if !sys::SteamAPI_ISteamInventory_GetItemDefinitionProperty(
+            if !sys::SteamAPI_ISteamInventory_GetResultItemProperty(
                 self.inventory,
-                item_def,
+                result_handle,
+                item_index,
                 property_name.as_ptr(),
                 std::ptr::null_mut(),
                 &mut value_len,
             ) {
-                return Err(InventoryError::GetItemDefinitionPropertyFailed);
+                return Err(InventoryError::GetResultItemPropertyFailed);
             }
-            
+
+            if value_len == 0 {
+                return Ok(String::new());
+            }
+
             let mut value_buffer: Vec<u8> = Vec::with_capacity(value_len as usize);
-            if sys::SteamAPI_ISteamInventory_GetItemDefinitionProperty(
+            if sys::SteamAPI_ISteamInventory_GetResultItemProperty(
                 self.inventory,
-                item_def,
+                result_handle,
+                item_index,
                 property_name.as_ptr(),
                 value_buffer.as_mut_ptr() as *mut i8,
                 &mut value_len,
@@ -128,11 +144,27 @@ impl<Manager> Inventory<Manager> {
                 let value = String::from_utf8(value_buffer).expect("Failed to convert value to string");
                 Ok(value)
             } else {
-                Err(InventoryError::GetItemDefinitionPropertyFailed)
+                Err(InventoryError::GetResultItemPropertyFailed)
             }
         }
     }
 
+    /// Reads several dynamic per-instance properties for a single item in a result set,
+    /// folding them into a `HashMap` keyed by property name.
+    pub fn get_result_item_properties(
+        &self,
+        result_handle: sys::SteamInventoryResult_t,
+        item_index: u32,
+        property_names: &[&str],
+    ) -> Result<HashMap<String, String>, InventoryError> {
+        let mut properties = HashMap::with_capacity(property_names.len());
+        for property_name in property_names {
+            let value = self.get_result_item_property(result_handle, item_index, property_name)?;
+            properties.insert((*property_name).to_string(), value);
+        }
+        Ok(properties)
+    }
+
     pub fn trigger_item_drop(&self, drop_list_definition: sys::SteamItemDef_t) -> Result<sys::SteamInventoryResult_t, InventoryError> {
         let mut result_handle = sys::k_SteamInventoryResultInvalid;
         unsafe {
@@ -155,6 +187,68 @@ impl<Manager> Inventory<Manager> {
         }
     }
 
+    /// Crafts `outputs` from `inputs`, consuming the input item stacks to produce the output
+    /// item definitions in the given quantities. Returns a pending result handle resolved
+    /// through the same [`SteamInventoryResultReady`] channel-based flow as
+    /// [`Inventory::get_all_items`].
+    pub fn exchange_items(
+        &self,
+        outputs: &[(SteamItemDef, u32)],
+        inputs: &[(SteamItemInstanceID, u32)],
+    ) -> Result<sys::SteamInventoryResult_t, InventoryError> {
+        let output_defs: Vec<sys::SteamItemDef_t> = outputs.iter().map(|(def, _)| def.0).collect();
+        let output_quantities: Vec<u32> = outputs.iter().map(|(_, quantity)| *quantity).collect();
+        let input_ids: Vec<sys::SteamItemInstanceID_t> = inputs.iter().map(|(id, _)| id.0).collect();
+        let input_quantities: Vec<u32> = inputs.iter().map(|(_, quantity)| *quantity).collect();
+
+        let mut result_handle = sys::k_SteamInventoryResultInvalid;
+        unsafe {
+            if sys::SteamAPI_ISteamInventory_ExchangeItems(
+                self.inventory,
+                &mut result_handle,
+                output_defs.as_ptr(),
+                output_quantities.as_ptr(),
+                output_defs.len() as u32,
+                input_ids.as_ptr(),
+                input_quantities.as_ptr(),
+                input_ids.len() as u32,
+            ) {
+                Ok(result_handle)
+            } else {
+                Err(InventoryError::ExchangeItemsFailed)
+            }
+        }
+    }
+
+    /// Splits or merges item stacks. When `dest` is `None`, Steam allocates a brand-new
+    /// stack for `quantity` (a split); when `dest` is `Some`, `quantity` is moved onto the
+    /// existing target stack (a merge). Returns a pending result handle resolved the same
+    /// way as [`Inventory::get_all_items`]; confirm the resulting quantities with
+    /// [`Inventory::get_result_items`] once `SteamInventoryResultReady` fires.
+    pub fn transfer_item_quantity(
+        &self,
+        item: SteamItemInstanceID,
+        quantity: u32,
+        dest: Option<SteamItemInstanceID>,
+    ) -> Result<sys::SteamInventoryResult_t, InventoryError> {
+        let dest_id = dest.map_or(sys::k_SteamItemInstanceIDInvalid, |dest| dest.0);
+
+        let mut result_handle = sys::k_SteamInventoryResultInvalid;
+        unsafe {
+            if sys::SteamAPI_ISteamInventory_TransferItemQuantity(
+                self.inventory,
+                &mut result_handle,
+                item.0,
+                quantity,
+                dest_id,
+            ) {
+                Ok(result_handle)
+            } else {
+                Err(InventoryError::TransferFailed)
+            }
+        }
+    }
+
     /// Retrieves all items in the user's Steam inventory.
     pub fn get_all_items(&self) -> Result<sys::SteamInventoryResult_t, InventoryError> {
         let mut result_handle = sys::k_SteamInventoryResultInvalid;
@@ -167,6 +261,26 @@ impl<Manager> Inventory<Manager> {
         }
     }
 
+    /// Refreshes just the given instances instead of pulling the whole inventory with
+    /// [`Inventory::get_all_items`]. Returns a pending result handle resolved the same way.
+    pub fn get_items_by_id(&self, instance_ids: &[SteamItemInstanceID]) -> Result<sys::SteamInventoryResult_t, InventoryError> {
+        let instance_ids: Vec<sys::SteamItemInstanceID_t> = instance_ids.iter().map(|id| id.0).collect();
+
+        let mut result_handle = sys::k_SteamInventoryResultInvalid;
+        unsafe {
+            if sys::SteamAPI_ISteamInventory_GetItemsByID(
+                self.inventory,
+                &mut result_handle,
+                instance_ids.as_ptr(),
+                instance_ids.len() as u32,
+            ) {
+                Ok(result_handle)
+            } else {
+                Err(InventoryError::GetItemsByIDFailed)
+            }
+        }
+    }
+
     /// Retrieves the status of a result handle.
     pub fn get_result_status(&self, result_handle: sys::SteamInventoryResult_t) -> Result<sys::EResult, InventoryError> {
         unsafe {
@@ -182,36 +296,81 @@ impl<Manager> Inventory<Manager> {
         }
     }
 
+    /// Retrieves the Unix timestamp Steam attached to a result, so server-side verifiers can
+    /// reject snapshots (e.g. from [`Inventory::deserialize_result`]) older than some
+    /// freshness window.
+    pub fn get_result_timestamp(&self, result_handle: sys::SteamInventoryResult_t) -> Result<u32, InventoryError> {
+        unsafe {
+            let timestamp = sys::SteamAPI_ISteamInventory_GetResultTimestamp(
+                self.inventory,
+                result_handle,
+            );
+            if timestamp == 0 {
+                Err(InventoryError::GetResultTimestampFailed)
+            } else {
+                Ok(timestamp)
+            }
+        }
+    }
+
     /// Retrieves the detailed list of items from the inventory given a result handle.
     pub fn get_result_items(&self, result_handle: sys::SteamInventoryResult_t) -> Result<Vec<SteamItemDetails>, InventoryError> {
-        let mut items_count = 0;
+        get_result_items_raw(self.inventory, result_handle)
+    }
+
+    /// Serializes a result handle into a Steam-signed blob that can be handed to a trusted
+    /// game server, which can then reconstruct it with [`Inventory::deserialize_result`]
+    /// without trusting the client's own view of its inventory.
+    pub fn serialize_result(&self, result_handle: sys::SteamInventoryResult_t) -> Result<Vec<u8>, InventoryError> {
+        let mut buffer_len = 0;
         unsafe {
-            if !sys::SteamAPI_ISteamInventory_GetResultItems(
+            if !sys::SteamAPI_ISteamInventory_SerializeResult(
                 self.inventory,
                 result_handle,
                 std::ptr::null_mut(),
-                &mut items_count,
+                &mut buffer_len,
             ) {
-                return Err(InventoryError::GetResultItemsFailed);
+                return Err(InventoryError::SerializeResultFailed);
             }
 
-            let mut items_array: Vec<sys::SteamItemDetails_t> = Vec::with_capacity(items_count as usize);
-            if sys::SteamAPI_ISteamInventory_GetResultItems(
+            let mut buffer: Vec<u8> = Vec::with_capacity(buffer_len as usize);
+            if sys::SteamAPI_ISteamInventory_SerializeResult(
                 self.inventory,
                 result_handle,
-                items_array.as_mut_ptr(),
-                &mut items_count,
+                buffer.as_mut_ptr() as *mut c_void,
+                &mut buffer_len,
             ) {
-                items_array.set_len(items_count as usize);
-                let items = items_array.into_iter().map(|details| SteamItemDetails {
-                    item_id: SteamItemInstanceID(details.m_itemId),
-                    definition: SteamItemDef(details.m_iDefinition),
-                    quantity: details.m_unQuantity,
-                    flags: details.m_unFlags,
-                }).collect();
-                Ok(items)
+                buffer.set_len(buffer_len as usize);
+                Ok(buffer)
             } else {
-                Err(InventoryError::GetResultItemsFailed)
+                Err(InventoryError::SerializeResultFailed)
+            }
+        }
+    }
+
+    /// Reconstructs a read-only result handle from a blob produced by
+    /// [`Inventory::serialize_result`]. A tampered or stale blob does not fail outright;
+    /// instead it deserializes into a handle whose status is expired, so this checks
+    /// [`Inventory::get_result_status`] itself and surfaces that as
+    /// [`InventoryError::ResultExpired`] rather than handing back an unusable handle.
+    pub fn deserialize_result(&self, buffer: &[u8]) -> Result<sys::SteamInventoryResult_t, InventoryError> {
+        let mut result_handle = sys::k_SteamInventoryResultInvalid;
+        unsafe {
+            if !sys::SteamAPI_ISteamInventory_DeserializeResult(
+                self.inventory,
+                &mut result_handle,
+                buffer.as_ptr() as *const c_void,
+                buffer.len() as u32,
+                false,
+            ) {
+                return Err(InventoryError::DeserializeResultFailed);
+            }
+
+            let status = sys::SteamAPI_ISteamInventory_GetResultStatus(self.inventory, result_handle);
+            match status {
+                sys::EResult::k_EResultOK => Ok(result_handle),
+                sys::EResult::k_EResultExpired => Err(InventoryError::ResultExpired),
+                _ => Err(InventoryError::DeserializeResultFailed),
             }
         }
     }
@@ -227,6 +386,195 @@ impl<Manager> Inventory<Manager> {
     }
 }
 
+/// Shared by [`Inventory::get_item_definitions_ids`] and [`ItemCatalog`], which both need
+/// to enumerate definition IDs without holding a borrow of `Inventory` itself.
+fn get_item_definitions_ids_raw(inventory: *mut sys::ISteamInventory) -> Result<Vec<sys::SteamItemDef_t>, InventoryError> {
+    let mut item_defs_count = 0;
+    unsafe {
+        if !sys::SteamAPI_ISteamInventory_GetItemDefinitionIDs(
+            inventory,
+            std::ptr::null_mut(),
+            &mut item_defs_count,
+        ) {
+            return Err(InventoryError::GetItemDefinitionIDsFailed);
+        }
+
+        let mut item_defs_array: Vec<sys::SteamItemDef_t> = Vec::with_capacity(item_defs_count as usize);
+        if sys::SteamAPI_ISteamInventory_GetItemDefinitionIDs(
+            inventory,
+            item_defs_array.as_mut_ptr(),
+            &mut item_defs_count,
+        ) {
+            item_defs_array.set_len(item_defs_count as usize);
+            Ok(item_defs_array)
+        } else {
+            Err(InventoryError::GetItemDefinitionIDsFailed)
+        }
+    }
+}
+
+/// Shared by [`Inventory::get_item_definition_property`] and [`ItemCatalog`].
+fn get_item_definition_property_raw(inventory: *mut sys::ISteamInventory, item_def: sys::SteamItemDef_t, property_name: &str) -> Result<String, InventoryError> {
+    let property_name = CString::new(property_name).expect("CString::new failed");
+    let mut value_len = 0;
+    unsafe {
+        if !sys::SteamAPI_ISteamInventory_GetItemDefinitionProperty(
+            inventory,
+            item_def,
+            property_name.as_ptr(),
+            std::ptr::null_mut(),
+            &mut value_len,
+        ) {
+            return Err(InventoryError::GetItemDefinitionPropertyFailed);
+        }
+
+        let mut value_buffer: Vec<u8> = Vec::with_capacity(value_len as usize);
+        if sys::SteamAPI_ISteamInventory_GetItemDefinitionProperty(
+            inventory,
+            item_def,
+            property_name.as_ptr(),
+            value_buffer.as_mut_ptr() as *mut i8,
+            &mut value_len,
+        ) {
+            value_buffer.set_len((value_len - 1) as usize);
+            let value = String::from_utf8(value_buffer).expect("Failed to convert value to string");
+            Ok(value)
+        } else {
+            Err(InventoryError::GetItemDefinitionPropertyFailed)
+        }
+    }
+}
+
+/// Shared by [`Inventory::get_result_items`] and the async callback below, which both need
+/// to turn a result handle into items without holding a borrow of `Inventory` itself.
+fn get_result_items_raw(inventory: *mut sys::ISteamInventory, result_handle: sys::SteamInventoryResult_t) -> Result<Vec<SteamItemDetails>, InventoryError> {
+    let mut items_count = 0;
+    unsafe {
+        if !sys::SteamAPI_ISteamInventory_GetResultItems(
+            inventory,
+            result_handle,
+            std::ptr::null_mut(),
+            &mut items_count,
+        ) {
+            return Err(InventoryError::GetResultItemsFailed);
+        }
+
+        let mut items_array: Vec<sys::SteamItemDetails_t> = Vec::with_capacity(items_count as usize);
+        if sys::SteamAPI_ISteamInventory_GetResultItems(
+            inventory,
+            result_handle,
+            items_array.as_mut_ptr(),
+            &mut items_count,
+        ) {
+            items_array.set_len(items_count as usize);
+            let items = items_array.into_iter().map(|details| SteamItemDetails {
+                item_id: SteamItemInstanceID(details.m_itemId),
+                definition: SteamItemDef(details.m_iDefinition),
+                quantity: details.m_unQuantity,
+                flags: details.m_unFlags,
+            }).collect();
+            Ok(items)
+        } else {
+            Err(InventoryError::GetResultItemsFailed)
+        }
+    }
+}
+
+impl<Manager> Inventory<Manager>
+where
+    Manager: 'static,
+{
+    /// Registers the single `SteamInventoryResultReady` callback that every `*_async`
+    /// method relies on to resolve its future, the first time any of them is called.
+    fn ensure_async_callback(&self) {
+        if self.async_registered.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let inventory = InventoryPtr(self.inventory);
+        let pending = self.async_pending.clone();
+        register_callback(&self._inner, move |val: SteamInventoryResultReady| {
+            let sender = match pending.lock().unwrap().remove(&val.handle) {
+                Some(sender) => sender,
+                None => return,
+            };
+
+            let result = match val.result {
+                Ok(()) => get_result_items_raw(inventory.0, val.handle),
+                Err(_) => Err(InventoryError::OperationFailed),
+            };
+
+            unsafe {
+                sys::SteamAPI_ISteamInventory_DestroyResult(inventory.0, val.handle);
+            }
+
+            let _ = sender.send(result);
+        });
+    }
+
+    /// Issues the operation returned by `issue` and registers its oneshot sender in the
+    /// pending-result map *before* releasing the map's lock, so the
+    /// `SteamInventoryResultReady` callback — which takes the same lock to look up the
+    /// sender — can never observe the handle before it has somewhere to deliver its result.
+    async fn await_result(
+        &self,
+        issue: impl FnOnce() -> Result<sys::SteamInventoryResult_t, InventoryError>,
+    ) -> Result<Vec<SteamItemDetails>, InventoryError> {
+        self.ensure_async_callback();
+        let (tx, rx) = oneshot::channel();
+        {
+            let mut pending = self.async_pending.lock().unwrap();
+            let result_handle = issue()?;
+            pending.insert(result_handle, tx);
+        }
+        rx.await.unwrap_or(Err(InventoryError::OperationFailed))
+    }
+
+    /// `.await`-able equivalent of [`Inventory::get_all_items`], removing the need to
+    /// hand-match the result handle against a `SteamInventoryResultReady` channel.
+    pub async fn get_all_items_async(&self) -> Result<Vec<SteamItemDetails>, InventoryError> {
+        self.await_result(|| self.get_all_items()).await
+    }
+
+    /// `.await`-able equivalent of [`Inventory::consume_item`].
+    pub async fn consume_item_async(&self, item_consume: sys::SteamItemInstanceID_t, quantity: u32) -> Result<Vec<SteamItemDetails>, InventoryError> {
+        self.await_result(|| self.consume_item(item_consume, quantity)).await
+    }
+
+    /// `.await`-able equivalent of [`Inventory::exchange_items`].
+    pub async fn exchange_items_async(&self, outputs: &[(SteamItemDef, u32)], inputs: &[(SteamItemInstanceID, u32)]) -> Result<Vec<SteamItemDetails>, InventoryError> {
+        self.await_result(|| self.exchange_items(outputs, inputs)).await
+    }
+
+    /// Registers the single `SteamInventoryDefinitionUpdate` callback that flips
+    /// `catalog_need_reload`, the first time [`Inventory::catalog`] is called.
+    fn ensure_catalog_callback(&self) {
+        if self.catalog_registered.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let need_reload = self.catalog_need_reload.clone();
+        register_callback(&self._inner, move |_val: SteamInventoryDefinitionUpdate| {
+            need_reload.store(true, Ordering::SeqCst);
+        });
+    }
+
+    /// Returns a cheap handle to the item definition catalog. The underlying cache and
+    /// `SteamInventoryDefinitionUpdate` callback are shared across every call to `catalog()`
+    /// on handles obtained from the same client, so calling this per-lookup (as
+    /// `client.inventory().catalog()...` idiom elsewhere in this crate does) neither leaks
+    /// callbacks nor forces a reload every time.
+    pub fn catalog(&self) -> ItemCatalog<Manager> {
+        self.ensure_catalog_callback();
+        ItemCatalog {
+            inventory: InventoryPtr(self.inventory),
+            _inner: self._inner.clone(),
+            cache: self.catalog_cache.clone(),
+            need_reload: self.catalog_need_reload.clone(),
+        }
+    }
+}
+
 /// Represents an individual inventory item with its unique details.
 #[derive(Clone, Debug)]
 pub struct SteamItemDetails {
@@ -241,7 +589,7 @@ pub struct SteamItemDetails {
 pub struct SteamItemInstanceID(pub u64);
 
 /// Represents a unique identifier for an item definition.
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct SteamItemDef(pub i32);
 
 /// Enumerates possible errors that can occur during inventory operations.
@@ -265,6 +613,106 @@ pub enum InventoryError {
     GetItemDefinitionIDsFailed,
     #[error("Failed to retrieve item definition property")]
     GetItemDefinitionPropertyFailed,
+    #[error("Failed to retrieve result item property")]
+    GetResultItemPropertyFailed,
+    #[error("Failed to serialize result")]
+    SerializeResultFailed,
+    #[error("Failed to deserialize result")]
+    DeserializeResultFailed,
+    #[error("The result handle has expired or was tampered with")]
+    ResultExpired,
+    #[error("Failed to exchange items")]
+    ExchangeItemsFailed,
+    #[error("Failed to transfer item quantity")]
+    TransferFailed,
+    #[error("Failed to retrieve result timestamp")]
+    GetResultTimestampFailed,
+    #[error("Failed to retrieve items by ID")]
+    GetItemsByIDFailed,
+}
+
+/// A cached, structured view of the item definition catalog. Built with
+/// [`Inventory::catalog`]; eagerly loads the `name`, `type`, `tradable`, `marketable` and
+/// `price` properties of every definition on first lookup and reloads whenever Steam fires
+/// [`SteamInventoryDefinitionUpdate`], so lookups never hit the native API on the hot path.
+/// Other definition properties are not cached.
+pub struct ItemCatalog<Manager> {
+    inventory: InventoryPtr,
+    _inner: Arc<Inner<Manager>>,
+    cache: Arc<CatalogCache>,
+    need_reload: Arc<AtomicBool>,
+}
+
+const CATALOG_PROPERTIES: &[&str] = &["name", "type", "tradable", "marketable", "price"];
+
+impl<Manager> ItemCatalog<Manager> {
+    fn reload_if_needed(&self) -> Result<(), InventoryError> {
+        if !self.need_reload.swap(false, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let def_ids = get_item_definitions_ids_raw(self.inventory.0)?;
+        let mut cache = self.cache.lock().unwrap();
+        cache.clear();
+        for def_id in def_ids {
+            let mut properties = HashMap::with_capacity(CATALOG_PROPERTIES.len());
+            for property_name in CATALOG_PROPERTIES {
+                if let Ok(value) = get_item_definition_property_raw(self.inventory.0, def_id, property_name) {
+                    properties.insert((*property_name).to_string(), value);
+                }
+            }
+            cache.insert(SteamItemDef(def_id), properties);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the cached property map for `def`, reloading the catalog first if Steam has
+    /// signalled a definition update since the last load.
+    pub fn definition(&self, def: SteamItemDef) -> Result<Option<HashMap<String, String>>, InventoryError> {
+        self.reload_if_needed()?;
+        Ok(self.cache.lock().unwrap().get(&def).cloned())
+    }
+
+    /// Returns a single cached property of `def` by name; a name outside the cached set
+    /// yields `None`.
+    pub fn property(&self, def: SteamItemDef, name: &str) -> Result<Option<String>, InventoryError> {
+        Ok(self.definition(def)?.and_then(|properties| properties.get(name).cloned()))
+    }
+
+    /// The item's display name.
+    pub fn name(&self, def: SteamItemDef) -> Result<Option<String>, InventoryError> {
+        self.property(def, "name")
+    }
+
+    /// The item's type, as defined in the Steamworks item schema.
+    pub fn item_type(&self, def: SteamItemDef) -> Result<Option<String>, InventoryError> {
+        self.property(def, "type")
+    }
+
+    /// Whether the item can be traded, parsed from the cached `"tradable"` property
+    /// (`"0"` and `"false"` read as `false`, any other value as `true`).
+    pub fn tradable(&self, def: SteamItemDef) -> Result<Option<bool>, InventoryError> {
+        Ok(self.property(def, "tradable")?.map(|value| !matches!(value.as_str(), "0" | "false")))
+    }
+
+    /// Whether the item can be sold on the Steam Community Market, parsed from the cached
+    /// `"marketable"` property (`"0"` and `"false"` read as `false`, any other value as `true`).
+    pub fn marketable(&self, def: SteamItemDef) -> Result<Option<bool>, InventoryError> {
+        Ok(self.property(def, "marketable")?.map(|value| !matches!(value.as_str(), "0" | "false")))
+    }
+
+    /// The item's store price, parsed from the cached `"price"` property as a plain decimal
+    /// number. Yields `None` when the property is missing or is not a plain number.
+    pub fn price(&self, def: SteamItemDef) -> Result<Option<f64>, InventoryError> {
+        Ok(self.property(def, "price")?.and_then(|value| value.parse().ok()))
+    }
+
+    /// Forces the next lookup to reload every definition's cached properties, even if Steam has not
+    /// fired [`SteamInventoryDefinitionUpdate`].
+    pub fn invalidate(&self) {
+        self.need_reload.store(true, Ordering::SeqCst);
+    }
 }
 
 #[cfg(test)]
@@ -303,4 +751,243 @@ mod tests {
         }
         panic!("Timed out waiting for inventory result.");
     }
+
+    #[test]
+    fn test_get_result_item_property() {
+        let client = Client::init().unwrap();
+        let (tx, rx) = mpsc::channel::<sys::SteamInventoryResult_t>();
+
+        client.register_callback(move |val: SteamInventoryResultReady| {
+            if let Ok(()) = val.result {
+                tx.send(val.handle).expect("Failed to send handle");
+            }
+        });
+
+        let _result = client.inventory().get_all_items();
+
+        for _ in 0..50 {
+            client.run_callbacks();
+            ::std::thread::sleep(::std::time::Duration::from_millis(100));
+            if let Ok(handle) = rx.try_recv() {
+                let items = client.inventory().get_result_items(handle).unwrap();
+                assert!(!items.is_empty(), "No items received");
+
+                let properties = client
+                    .inventory()
+                    .get_result_item_properties(handle, 0, &["name"])
+                    .unwrap();
+                assert!(properties.contains_key("name"));
+
+                client.inventory().destroy_result(handle);
+                return;
+            }
+        }
+        panic!("Timed out waiting for inventory result.");
+    }
+
+    #[test]
+    fn test_serialize_deserialize_result() {
+        let client = Client::init().unwrap();
+        let (tx, rx) = mpsc::channel::<sys::SteamInventoryResult_t>();
+
+        client.register_callback(move |val: SteamInventoryResultReady| {
+            if let Ok(()) = val.result {
+                tx.send(val.handle).expect("Failed to send handle");
+            }
+        });
+
+        let _result = client.inventory().get_all_items();
+
+        for _ in 0..50 {
+            client.run_callbacks();
+            ::std::thread::sleep(::std::time::Duration::from_millis(100));
+            if let Ok(handle) = rx.try_recv() {
+                let inventory = client.inventory();
+                let blob = inventory.serialize_result(handle).unwrap();
+                assert!(!blob.is_empty(), "Serialized result was empty");
+
+                let restored_handle = inventory.deserialize_result(&blob).unwrap();
+                let items = inventory.get_result_items(restored_handle).unwrap();
+                assert!(!items.is_empty(), "No items received from deserialized result");
+
+                inventory.destroy_result(restored_handle);
+                inventory.destroy_result(handle);
+                return;
+            }
+        }
+        panic!("Timed out waiting for inventory result.");
+    }
+
+    #[test]
+    fn test_exchange_items() {
+        let client = Client::init().unwrap();
+        let (tx, rx) = mpsc::channel::<sys::SteamInventoryResult_t>();
+
+        client.register_callback(move |val: SteamInventoryResultReady| {
+            if let Ok(()) = val.result {
+                tx.send(val.handle).expect("Failed to send handle");
+            }
+        });
+
+        let outputs = [(SteamItemDef(1), 1)];
+        let inputs: [(SteamItemInstanceID, u32); 0] = [];
+        let result = client.inventory().exchange_items(&outputs, &inputs);
+        assert!(result.is_ok(), "exchange_items failed: {:?}", result);
+
+        for _ in 0..50 {
+            client.run_callbacks();
+            ::std::thread::sleep(::std::time::Duration::from_millis(100));
+            if let Ok(handle) = rx.try_recv() {
+                let items = client.inventory().get_result_items(handle).unwrap();
+                assert!(
+                    items.iter().any(|item| item.definition == SteamItemDef(1)),
+                    "Expected the crafted output definition among the resulting items, got {:?}",
+                    items
+                );
+                client.inventory().destroy_result(handle);
+                return;
+            }
+        }
+        panic!("Timed out waiting for inventory result.");
+    }
+
+    #[test]
+    fn test_get_all_items_async() {
+        let client = Client::init().unwrap();
+        let inventory = client.inventory();
+
+        let items = futures::executor::block_on(async {
+            let call = inventory.get_all_items_async();
+            futures::pin_mut!(call);
+            loop {
+                client.run_callbacks();
+                match futures::poll!(&mut call) {
+                    std::task::Poll::Ready(result) => break result,
+                    std::task::Poll::Pending => {
+                        ::std::thread::sleep(::std::time::Duration::from_millis(100));
+                    }
+                }
+            }
+        }).unwrap();
+
+        assert!(!items.is_empty(), "No items received");
+    }
+
+    #[test]
+    fn test_item_catalog_reload() {
+        let client = Client::init().unwrap();
+        client.inventory().load_item_definitions().unwrap();
+
+        // Calling `catalog()` again, the way callers are expected to per-lookup, must reuse
+        // the same cache rather than forcing a reload and leaking another callback.
+        let first = client.inventory().catalog();
+        let second = client.inventory().catalog();
+
+        let def_ids = client.inventory().get_item_definitions_ids().unwrap();
+        assert!(!def_ids.is_empty(), "No item definitions loaded");
+        let def = SteamItemDef(def_ids[0]);
+
+        let name = first.name(def).unwrap();
+        assert!(name.is_some(), "Expected a cached name for the first definition");
+        assert!(Arc::ptr_eq(&first.cache, &second.cache), "Catalog cache was not shared");
+        assert_eq!(second.name(def).unwrap(), name, "Second catalog handle returned a different name");
+
+        second.invalidate();
+        assert_eq!(first.name(def).unwrap(), name, "Reload did not reproduce the same data");
+    }
+
+    #[test]
+    fn test_transfer_item_quantity() {
+        let client = Client::init().unwrap();
+        let (tx, rx) = mpsc::channel::<sys::SteamInventoryResult_t>();
+
+        client.register_callback(move |val: SteamInventoryResultReady| {
+            if let Ok(()) = val.result {
+                tx.send(val.handle).expect("Failed to send handle");
+            }
+        });
+
+        let _all_items = client.inventory().get_all_items();
+
+        for _ in 0..50 {
+            client.run_callbacks();
+            ::std::thread::sleep(::std::time::Duration::from_millis(100));
+            if let Ok(handle) = rx.try_recv() {
+                let items = client.inventory().get_result_items(handle).unwrap();
+                assert!(!items.is_empty(), "No items received");
+                client.inventory().destroy_result(handle);
+
+                // Split one unit off the first owned stack into a brand-new stack.
+                let item_id = items[0].item_id.clone();
+                let result = client.inventory().transfer_item_quantity(item_id, 1, None);
+                assert!(result.is_ok(), "transfer_item_quantity failed: {:?}", result);
+
+                for _ in 0..50 {
+                    client.run_callbacks();
+                    ::std::thread::sleep(::std::time::Duration::from_millis(100));
+                    if let Ok(transfer_handle) = rx.try_recv() {
+                        let transferred_items = client.inventory().get_result_items(transfer_handle).unwrap();
+                        assert!(
+                            transferred_items.iter().any(|item| item.quantity == 1),
+                            "Expected a new stack of quantity 1 among the transfer result, got {:?}",
+                            transferred_items
+                        );
+                        client.inventory().destroy_result(transfer_handle);
+                        return;
+                    }
+                }
+                panic!("Timed out waiting for transfer result.");
+            }
+        }
+        panic!("Timed out waiting for inventory result.");
+    }
+
+    #[test]
+    fn test_get_items_by_id_and_timestamp() {
+        let client = Client::init().unwrap();
+        let (tx, rx) = mpsc::channel::<sys::SteamInventoryResult_t>();
+
+        client.register_callback(move |val: SteamInventoryResultReady| {
+            if let Ok(()) = val.result {
+                tx.send(val.handle).expect("Failed to send handle");
+            }
+        });
+
+        let _all_items = client.inventory().get_all_items();
+
+        for _ in 0..50 {
+            client.run_callbacks();
+            ::std::thread::sleep(::std::time::Duration::from_millis(100));
+            if let Ok(handle) = rx.try_recv() {
+                let items = client.inventory().get_result_items(handle).unwrap();
+                assert!(!items.is_empty(), "No items received");
+                let timestamp = client.inventory().get_result_timestamp(handle).unwrap();
+                assert!(timestamp > 0, "Expected a non-zero result timestamp");
+                client.inventory().destroy_result(handle);
+
+                let ids: Vec<SteamItemInstanceID> = items.iter().map(|item| item.item_id.clone()).collect();
+                let result = client.inventory().get_items_by_id(&ids);
+                assert!(result.is_ok(), "get_items_by_id failed: {:?}", result);
+                let by_id_handle = result.unwrap();
+
+                for _ in 0..50 {
+                    client.run_callbacks();
+                    ::std::thread::sleep(::std::time::Duration::from_millis(100));
+                    if let Ok(received_handle) = rx.try_recv() {
+                        assert_eq!(received_handle, by_id_handle, "Received an unexpected result handle");
+                        let by_id_items = client.inventory().get_result_items(by_id_handle).unwrap();
+                        let mut returned_ids: Vec<u64> = by_id_items.iter().map(|item| item.item_id.0).collect();
+                        let mut expected_ids: Vec<u64> = ids.iter().map(|id| id.0).collect();
+                        returned_ids.sort_unstable();
+                        expected_ids.sort_unstable();
+                        assert_eq!(returned_ids, expected_ids, "get_items_by_id did not return the requested instances");
+                        client.inventory().destroy_result(by_id_handle);
+                        return;
+                    }
+                }
+                panic!("Timed out waiting for get_items_by_id result.");
+            }
+        }
+        panic!("Timed out waiting for inventory result.");
+    }
 }
\ No newline at end of file